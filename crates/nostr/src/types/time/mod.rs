@@ -26,6 +26,27 @@ const DAYS_PER_400Y: i64 = 365 * 400 + 97;
 const DAYS_PER_100Y: i64 = 365 * 100 + 24;
 const DAYS_PER_4Y: i64 = 365 * 4 + 1;
 
+/// Time error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Invalid RFC 3339 datetime
+    InvalidRfc3339,
+    /// Datetime is before the UNIX epoch
+    BeforeUnixEpoch,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRfc3339 => write!(f, "Invalid RFC 3339 datetime"),
+            Self::BeforeUnixEpoch => write!(f, "Datetime is before the UNIX epoch"),
+        }
+    }
+}
+
 const TO_HUMAN_DATE_BUF: [u8; 20] = [
     b'0', b'0', b'0', b'0', b'-', b'0', b'0', b'-', b'0', b'0', b'T', b'0', b'0', b':', b'0', b'0',
     b':', b'0', b'0', b'Z',
@@ -60,6 +81,79 @@ impl Timestamp {
         Self::from_secs(u64::MAX)
     }
 
+    /// Parse an RFC 3339 datetime (i.e. `2023-04-21T07:04:45Z` or `2023-04-21T09:04:45.123+02:00`)
+    ///
+    /// Fractional seconds are truncated.
+    pub fn from_rfc3339(s: &str) -> Result<Self, Error> {
+        let b: &[u8] = s.as_bytes();
+
+        // Date and time without fractions and offset: `YYYY-MM-DDTHH:MM:SS`
+        if b.len() < 20
+            || b[4] != b'-'
+            || b[7] != b'-'
+            || !matches!(b[10], b'T' | b't' | b' ')
+            || b[13] != b':'
+            || b[16] != b':'
+        {
+            return Err(Error::InvalidRfc3339);
+        }
+
+        let year: i64 = parse_digits(&b[0..4])?;
+        let mon: i64 = parse_digits(&b[5..7])?;
+        let mday: i64 = parse_digits(&b[8..10])?;
+        let hour: i64 = parse_digits(&b[11..13])?;
+        let min: i64 = parse_digits(&b[14..16])?;
+        let sec: i64 = parse_digits(&b[17..19])?;
+
+        if !(1..=12).contains(&mon)
+            || mday < 1
+            || mday > days_in_month(year, mon)
+            || hour > 23
+            || min > 59
+            || sec > 60
+        {
+            return Err(Error::InvalidRfc3339);
+        }
+
+        // Skip fractional seconds
+        let mut rest: &[u8] = &b[19..];
+        if let Some((b'.', frac)) = rest.split_first() {
+            let len: usize = frac.iter().take_while(|c| c.is_ascii_digit()).count();
+            if len == 0 {
+                return Err(Error::InvalidRfc3339);
+            }
+            rest = &frac[len..];
+        }
+
+        // Offset
+        let offset: i64 = match rest {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+                let h: i64 = parse_digits(&[*h1, *h2])?;
+                let m: i64 = parse_digits(&[*m1, *m2])?;
+                if h > 23 || m > 59 {
+                    return Err(Error::InvalidRfc3339);
+                }
+                let offset: i64 = h * 3600 + m * 60;
+                if *sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => return Err(Error::InvalidRfc3339),
+        };
+
+        let days: i64 = days_from_civil(year, mon, mday);
+        let secs: i64 = days * 86400 + hour * 3600 + min * 60 + sec - offset;
+
+        if secs < 0 {
+            return Err(Error::BeforeUnixEpoch);
+        }
+
+        Ok(Self::from_secs(secs as u64))
+    }
+
     /// Get UNIX timestamp
     #[cfg(feature = "std")]
     pub fn now() -> Self {
@@ -210,6 +304,36 @@ impl Timestamp {
     }
 }
 
+fn parse_digits(b: &[u8]) -> Result<i64, Error> {
+    b.iter().try_fold(0, |acc, c| {
+        if c.is_ascii_digit() {
+            Ok(acc * 10 + i64::from(c - b'0'))
+        } else {
+            Err(Error::InvalidRfc3339)
+        }
+    })
+}
+
+fn days_in_month(year: i64, mon: i64) -> i64 {
+    match mon {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since UNIX epoch for a proleptic Gregorian date
+fn days_from_civil(year: i64, mon: i64, mday: i64) -> i64 {
+    let year: i64 = if mon <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let yoe: i64 = year - era * 400;
+    let mp: i64 = (mon + 9) % 12;
+    let doy: i64 = (153 * mp + 2) / 5 + mday - 1;
+    let doe: i64 = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
 impl Default for Timestamp {
     #[inline]
     fn default() -> Self {
@@ -296,6 +420,51 @@ mod tests {
             String::from("2023-04-21T07:04:45Z")
         );
     }
+
+    #[test]
+    fn test_timestamp_from_rfc3339() {
+        let expected = Timestamp::from(1682060685);
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-04-21T07:04:45Z").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-04-21T09:04:45.123+02:00").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-04-20T23:04:45-08:00").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("1970-01-01T00:00:00Z").unwrap(),
+            Timestamp::zero()
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339(&expected.to_human_datetime()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_timestamp_from_rfc3339_invalid() {
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-04-21").unwrap_err(),
+            Error::InvalidRfc3339
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-02-29T00:00:00Z").unwrap_err(),
+            Error::InvalidRfc3339
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("2023-04-21T07:04:45").unwrap_err(),
+            Error::InvalidRfc3339
+        );
+        assert_eq!(
+            Timestamp::from_rfc3339("1969-12-31T23:59:59Z").unwrap_err(),
+            Error::BeforeUnixEpoch
+        );
+    }
 }
 
 #[cfg(bench)]